@@ -5,7 +5,9 @@
 
 mod pegin_payout;
 
-pub use pegin_payout::PeginPayoutConnector;
+pub use pegin_payout::{
+    PeginPayoutConnector, OPTIMISTIC_PAYOUT_LEAF_INDEX, PAYOUT_LEAF_INDEX,
+};
 
 use bitcoin::key::XOnlyPublicKey;
 use bitcoin::taproot::TaprootSpendInfo;
@@ -28,14 +30,16 @@ pub trait Connector {
     }
 }
 
-/// Build an (N-of-N) multisig script using CHECKSIGADD
+/// Build a (threshold-of-N) multisig script using CHECKSIGADD
 ///
 /// Script structure:
 /// - <PubKey_0> OP_CHECKSIG
 /// - <PubKey_1> OP_CHECKSIGADD ... <PubKey_N> OP_CHECKSIGADD
-/// - <N> OP_NUMEQUAL (or OP_NUMEQUALVERIFY if verify=true)
+/// - <threshold> OP_GREATERTHANOREQUAL (or OP_NUMEQUAL when threshold == N, each with a
+///   VERIFY-suffixed variant if verify=true)
 pub fn build_multisig_script(
     pubkeys: &[XOnlyPublicKey],
+    threshold: usize,
     verify: bool,
 ) -> Result<Vec<u8>, &'static str> {
     if pubkeys.is_empty() {
@@ -43,6 +47,9 @@ pub fn build_multisig_script(
     }
 
     let n = pubkeys.len();
+    if threshold == 0 || threshold > n {
+        return Err("Threshold must be between 1 and the number of public keys");
+    }
 
     // First key uses OP_CHECKSIG
     let mut script_bytes = script! {
@@ -63,17 +70,18 @@ pub fn build_multisig_script(
         script_bytes.extend(add_script);
     }
 
-    // Add threshold check
-    let threshold_script = if verify {
-        script! {
-            { n as i64 }
-            OP_NUMEQUALVERIFY
+    // Add threshold check. When the threshold is exactly N, use the cheaper
+    // exact-match opcodes; otherwise accept any tally of at least `threshold`.
+    let threshold_script = if threshold == n {
+        if verify {
+            script! { { n as i64 } OP_NUMEQUALVERIFY }
+        } else {
+            script! { { n as i64 } OP_NUMEQUAL }
         }
+    } else if verify {
+        script! { { threshold as i64 } OP_GREATERTHANOREQUAL OP_VERIFY }
     } else {
-        script! {
-            { n as i64 }
-            OP_NUMEQUAL
-        }
+        script! { { threshold as i64 } OP_GREATERTHANOREQUAL }
     }
     .compile()
     .to_bytes();
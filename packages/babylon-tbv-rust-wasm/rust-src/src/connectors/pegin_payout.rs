@@ -4,27 +4,42 @@
 //! for the first output of PegIn transactions.
 
 use super::{build_multisig_script, combine_script_components, Connector};
+use crate::error::Error;
 use crate::UNSPENDABLE_PUBKEY;
 use bitcoin::key::XOnlyPublicKey;
+use bitcoin::secp256k1::schnorr::Signature as SchnorrSignature;
 use bitcoin::secp256k1::Secp256k1;
-use bitcoin::taproot::{LeafVersion, TapNodeHash, TaprootBuilder, TaprootSpendInfo};
-use bitcoin::ScriptBuf;
+use bitcoin::sighash::{Prevouts, SighashCache, TapSighashType};
+use bitcoin::taproot::{
+    ControlBlock, LeafVersion, TapLeafHash, TapNodeHash, TaprootBuilder, TaprootSpendInfo,
+};
+use bitcoin::{ScriptBuf, TapSighash, Transaction, TxOut, Witness};
 use bitcoin_script::script;
 
+/// Leaf index of the full payout path (depositor + vault provider + keeper/challenger quorum).
+pub const PAYOUT_LEAF_INDEX: usize = 0;
+/// Leaf index of the cooperative fast path (depositor + vault provider only).
+pub const OPTIMISTIC_PAYOUT_LEAF_INDEX: usize = 1;
+
 /// Represents the connector from the first output of PegIn to either
 /// a PayoutOptimistic transaction or Payout transaction.
 ///
-/// Script structure:
-/// - Depositor must sign (CHECKSIGVERIFY)
-/// - Vault Provider must sign (CHECKSIGVERIFY)
-/// - All Vault Keepers must sign (N-of-N multisig)
-/// - All Universal Challengers must sign (combined with VKs for (N+M)-of-(N+M))
+/// Holds two alternative taproot leaves:
+/// - The full payout path ([`PAYOUT_LEAF_INDEX`]):
+///   - Depositor must sign (CHECKSIGVERIFY)
+///   - Vault Provider must sign (CHECKSIGVERIFY)
+///   - At least `keeper_threshold` of the Vault Keepers must sign
+///   - At least `challenger_threshold` of the Universal Challengers must sign (when any are configured)
+/// - The cooperative fast path ([`OPTIMISTIC_PAYOUT_LEAF_INDEX`]), requiring only the
+///   depositor and vault provider to sign.
 #[derive(Clone, Debug)]
 pub struct PeginPayoutConnector {
     pub depositor: XOnlyPublicKey,
     pub vault_provider: XOnlyPublicKey,
     pub vault_keepers: Vec<XOnlyPublicKey>,
     pub universal_challengers: Vec<XOnlyPublicKey>,
+    pub keeper_threshold: usize,
+    pub challenger_threshold: usize,
 }
 
 impl PeginPayoutConnector {
@@ -32,22 +47,41 @@ impl PeginPayoutConnector {
     ///
     /// # Errors
     ///
-    /// Returns an error if vault_keepers vector is empty.
+    /// Returns an error if `vault_keepers` is empty, if `keeper_threshold` is not
+    /// between 1 and `vault_keepers.len()`, or if `challenger_threshold` is not
+    /// between 1 and `universal_challengers.len()` (it must be 0 when there are no
+    /// universal challengers).
     pub fn new(
         depositor: XOnlyPublicKey,
         vault_provider: XOnlyPublicKey,
         vault_keepers: Vec<XOnlyPublicKey>,
         universal_challengers: Vec<XOnlyPublicKey>,
+        keeper_threshold: usize,
+        challenger_threshold: usize,
     ) -> Result<Self, &'static str> {
         if vault_keepers.is_empty() {
             return Err("At least one vault keeper is required");
         }
+        if keeper_threshold == 0 || keeper_threshold > vault_keepers.len() {
+            return Err("keeper_threshold must be between 1 and the number of vault keepers");
+        }
+        if universal_challengers.is_empty() {
+            if challenger_threshold != 0 {
+                return Err("challenger_threshold must be 0 when there are no universal challengers");
+            }
+        } else if challenger_threshold == 0 || challenger_threshold > universal_challengers.len() {
+            return Err(
+                "challenger_threshold must be between 1 and the number of universal challengers",
+            );
+        }
 
         Ok(Self {
             depositor,
             vault_provider,
             vault_keepers,
             universal_challengers,
+            keeper_threshold,
+            challenger_threshold,
         })
     }
 
@@ -57,8 +91,11 @@ impl PeginPayoutConnector {
     /// - <Depositor> OP_CHECKSIGVERIFY
     /// - <VaultProvider> OP_CHECKSIGVERIFY
     /// - <VaultKeeper_0> OP_CHECKSIG <VaultKeeper_1> OP_CHECKSIGADD ... <VaultKeeper_N> OP_CHECKSIGADD
-    /// - <UC_0> OP_CHECKSIGADD <UC_1> OP_CHECKSIGADD ... <UC_M> OP_CHECKSIGADD
-    /// - <N+M> OP_NUMEQUAL (enforcing (N+M)-of-(N+M) multisig for vault keepers + UCs)
+    /// - <keeper_threshold> OP_GREATERTHANOREQUAL, or OP_NUMEQUAL when keeper_threshold equals
+    ///   the number of keepers (VERIFY-suffixed only when universal challengers follow)
+    /// - <UC_0> OP_CHECKSIG <UC_1> OP_CHECKSIGADD ... <UC_M> OP_CHECKSIGADD
+    /// - <challenger_threshold> OP_GREATERTHANOREQUAL, or OP_NUMEQUAL when challenger_threshold
+    ///   equals the number of UCs (these two UC lines are omitted entirely when there are no UCs)
     pub fn generate_payout_script(&self) -> ScriptBuf {
         // Build role signatures (depositor and vault provider must sign)
         let role_sigs = script! {
@@ -70,16 +107,50 @@ impl PeginPayoutConnector {
         .compile()
         .to_bytes();
 
-        // Merge vault keepers and universal challengers for the multisig
-        let mut all_challengers = self.vault_keepers.clone();
-        all_challengers.extend_from_slice(&self.universal_challengers);
+        let has_challengers = !self.universal_challengers.is_empty();
+
+        // Vault keeper quorum; VERIFY-terminated when a challenger quorum follows it.
+        let keeper_multisig =
+            build_multisig_script(&self.vault_keepers, self.keeper_threshold, has_challengers)
+                .expect("Failed to build vault keeper multisig script");
 
-        // Build combined multisig ((N+M)-of-(N+M), uses OP_NUMEQUAL)
-        let challenger_multisig = build_multisig_script(&all_challengers, false)
-            .expect("Failed to build challenger multisig script");
+        let mut components = vec![role_sigs, keeper_multisig];
 
-        // Combine all components
-        combine_script_components(vec![role_sigs, challenger_multisig])
+        if has_challengers {
+            let challenger_multisig = build_multisig_script(
+                &self.universal_challengers,
+                self.challenger_threshold,
+                false,
+            )
+            .expect("Failed to build universal challenger multisig script");
+            components.push(challenger_multisig);
+        }
+
+        combine_script_components(components)
+    }
+
+    /// Generates the cooperative fast-path script: depositor and vault provider only.
+    ///
+    /// Script structure:
+    /// - <Depositor> OP_CHECKSIGVERIFY
+    /// - <VaultProvider> OP_CHECKSIG
+    pub fn generate_optimistic_payout_script(&self) -> ScriptBuf {
+        script! {
+            { self.depositor }
+            OP_CHECKSIGVERIFY
+            { self.vault_provider }
+            OP_CHECKSIG
+        }
+        .compile()
+    }
+
+    /// Returns every taproot leaf this connector can be spent through, indexed by
+    /// [`PAYOUT_LEAF_INDEX`] and [`OPTIMISTIC_PAYOUT_LEAF_INDEX`].
+    pub fn leaves(&self) -> Vec<ScriptBuf> {
+        vec![
+            self.generate_payout_script(),
+            self.generate_optimistic_payout_script(),
+        ]
     }
 
     /// Generates the taproot script hash for the pegin payout script.
@@ -87,18 +158,412 @@ impl PeginPayoutConnector {
         let payout_script = self.generate_payout_script();
         TapNodeHash::from_script(&payout_script, LeafVersion::TapScript)
     }
+
+    /// Renders the payout policy as a `tr()` output descriptor, importable by
+    /// descriptor-based wallets for watch-only tracking of the vault address.
+    ///
+    /// Encodes both taproot leaves: the full payout path (mirroring
+    /// [`Self::generate_payout_script`], via `and_v(v:pk(...))` role checks
+    /// followed by `multi_a` quorums) and the cooperative fast path (mirroring
+    /// [`Self::generate_optimistic_payout_script`]).
+    ///
+    /// `multi_a(k, ...)` always compiles to an exact `k`-of-n `OP_NUMEQUAL` tally, but
+    /// [`Self::generate_payout_script`] only uses `OP_NUMEQUAL` when the threshold
+    /// equals the key count — a partial (k < n) quorum instead uses
+    /// `OP_GREATERTHANOREQUAL`, which has no standard miniscript fragment. There is
+    /// therefore no descriptor that reproduces a partial-threshold payout script, so
+    /// this returns an error rather than silently describing a different leaf/address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error unless `keeper_threshold == vault_keepers.len()` and, when any
+    /// universal challengers are configured, `challenger_threshold == universal_challengers.len()`.
+    pub fn to_descriptor(&self) -> Result<String, Error> {
+        if self.keeper_threshold != self.vault_keepers.len() {
+            return Err(Error::ConnectorError(
+                "to_descriptor only supports an exact keeper_threshold == vault_keepers.len() quorum; \
+                 partial thresholds use OP_GREATERTHANOREQUAL, which has no multi_a equivalent"
+                    .to_string(),
+            ));
+        }
+        if !self.universal_challengers.is_empty()
+            && self.challenger_threshold != self.universal_challengers.len()
+        {
+            return Err(Error::ConnectorError(
+                "to_descriptor only supports an exact challenger_threshold == universal_challengers.len() quorum; \
+                 partial thresholds use OP_GREATERTHANOREQUAL, which has no multi_a equivalent"
+                    .to_string(),
+            ));
+        }
+
+        let payout_leaf = self.payout_descriptor_fragment();
+        let optimistic_leaf = format!("and_v(v:pk({}),pk({}))", self.depositor, self.vault_provider);
+
+        Ok(format!(
+            "tr({},{{{},{}}})",
+            crate::UNSPENDABLE_PUBKEY_STR,
+            payout_leaf,
+            optimistic_leaf
+        ))
+    }
+
+    fn payout_descriptor_fragment(&self) -> String {
+        let keeper_quorum = format!(
+            "multi_a({},{})",
+            self.keeper_threshold,
+            join_pubkeys(&self.vault_keepers)
+        );
+
+        let quorum = if self.universal_challengers.is_empty() {
+            keeper_quorum
+        } else {
+            let challenger_quorum = format!(
+                "multi_a({},{})",
+                self.challenger_threshold,
+                join_pubkeys(&self.universal_challengers)
+            );
+            format!("and_v(v:{},{})", keeper_quorum, challenger_quorum)
+        };
+
+        format!(
+            "and_v(v:pk({}),and_v(v:pk({}),{}))",
+            self.depositor, self.vault_provider, quorum
+        )
+    }
+
+    /// Computes the control block needed to spend `leaf_index` (see
+    /// [`PAYOUT_LEAF_INDEX`] / [`OPTIMISTIC_PAYOUT_LEAF_INDEX`]).
+    pub fn control_block_for(&self, leaf_index: usize) -> Result<ControlBlock, Error> {
+        let leaves = self.leaves();
+        let script = leaves
+            .get(leaf_index)
+            .ok_or_else(|| Error::ConnectorError(format!("invalid leaf index {}", leaf_index)))?;
+
+        self.generate_taproot_spend_info()
+            .control_block(&(script.clone(), LeafVersion::TapScript))
+            .ok_or_else(|| Error::ConnectorError("failed to compute control block".to_string()))
+    }
+
+    /// Computes the BIP-341 script-path sighash for spending `leaf_index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` - The spending transaction
+    /// * `input_index` - Index of the input spending the pegin output
+    /// * `prevouts` - All of the spending transaction's previous outputs, in input order
+    /// * `leaf_index` - Which leaf is being spent (see [`PAYOUT_LEAF_INDEX`] /
+    ///   [`OPTIMISTIC_PAYOUT_LEAF_INDEX`])
+    pub fn script_path_sighash(
+        &self,
+        tx: &Transaction,
+        input_index: usize,
+        prevouts: &[TxOut],
+        leaf_index: usize,
+    ) -> Result<TapSighash, Error> {
+        let leaves = self.leaves();
+        let script = leaves
+            .get(leaf_index)
+            .ok_or_else(|| Error::ConnectorError(format!("invalid leaf index {}", leaf_index)))?;
+        let leaf_hash = TapLeafHash::from_script(script, LeafVersion::TapScript);
+        let prevouts = Prevouts::All(prevouts);
+
+        SighashCache::new(tx)
+            .taproot_script_spend_signature_hash(
+                input_index,
+                &prevouts,
+                leaf_hash,
+                TapSighashType::Default,
+            )
+            .map_err(|e| Error::ConnectorError(format!("failed to compute script-path sighash: {}", e)))
+    }
+
+    /// Assembles the final witness stack for a script-path spend of `leaf_index`.
+    ///
+    /// `signatures_in_script_order` must contain exactly one slot per key in the
+    /// selected leaf's script, in the same order those keys appear in the script
+    /// (e.g. for [`PAYOUT_LEAF_INDEX`]: depositor, vault provider, then each
+    /// keeper/challenger). A `None` slot is for a keeper/challenger who didn't sign;
+    /// per BIP-342, a `CHECKSIGADD` key that doesn't contribute to the quorum must get
+    /// the empty byte vector on the stack, not a fabricated signature, so that it's
+    /// skipped for free rather than spending a sigop-budget verification on it. The
+    /// depositor and vault-provider slots are mandatory (`CHECKSIGVERIFY`) and should
+    /// always be `Some`. Since each `OP_CHECKSIG`-family op consumes the top of the
+    /// stack and the script's first op runs first, the signatures are pushed onto the
+    /// witness in reverse so that the first key's slot ends up on top, closest to the
+    /// script itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `leaf_index` is invalid, or if
+    /// `signatures_in_script_order` doesn't contain exactly one slot per key in that
+    /// leaf's script.
+    pub fn build_script_path_witness(
+        &self,
+        leaf_index: usize,
+        signatures_in_script_order: Vec<Option<SchnorrSignature>>,
+    ) -> Result<Witness, Error> {
+        let leaves = self.leaves();
+        let script = leaves
+            .get(leaf_index)
+            .ok_or_else(|| Error::ConnectorError(format!("invalid leaf index {}", leaf_index)))?
+            .clone();
+        let control_block = self.control_block_for(leaf_index)?;
+
+        let expected = self.leaf_key_count(leaf_index);
+        if signatures_in_script_order.len() != expected {
+            return Err(Error::ConnectorError(format!(
+                "leaf {} expects {} signature slots, got {}",
+                leaf_index,
+                expected,
+                signatures_in_script_order.len()
+            )));
+        }
+
+        let mut witness = Witness::new();
+        for signature in signatures_in_script_order.into_iter().rev() {
+            match signature {
+                Some(signature) => witness.push(signature.serialize()),
+                None => witness.push([]),
+            }
+        }
+        witness.push(script.as_bytes());
+        witness.push(control_block.serialize());
+
+        Ok(witness)
+    }
+
+    /// Number of keys, and therefore signature slots, in `leaf_index`'s script:
+    /// depositor + vault provider, plus one per keeper and (when any are configured)
+    /// one per universal challenger for [`PAYOUT_LEAF_INDEX`]; just depositor + vault
+    /// provider for [`OPTIMISTIC_PAYOUT_LEAF_INDEX`].
+    fn leaf_key_count(&self, leaf_index: usize) -> usize {
+        if leaf_index == PAYOUT_LEAF_INDEX {
+            2 + self.vault_keepers.len() + self.universal_challengers.len()
+        } else {
+            2
+        }
+    }
 }
 
 impl Connector for PeginPayoutConnector {
     fn generate_taproot_spend_info(&self) -> TaprootSpendInfo {
         let secp = Secp256k1::new();
-        let payout_script = self.generate_payout_script();
-        let unspendable_pubkey = *UNSPENDABLE_PUBKEY;
+        let leaves = self.leaves();
+        let depths = leaf_depths(leaves.len());
 
-        TaprootBuilder::new()
-            .add_leaf(0, payout_script)
-            .expect("Failed to add payout script leaf")
-            .finalize(&secp, unspendable_pubkey)
+        let mut builder = TaprootBuilder::new();
+        for (script, depth) in leaves.into_iter().zip(depths) {
+            builder = builder
+                .add_leaf(depth, script)
+                .expect("Failed to add taproot leaf");
+        }
+
+        builder
+            .finalize(&secp, *UNSPENDABLE_PUBKEY)
             .expect("Failed to create taproot spend info")
     }
 }
+
+/// Joins x-only public keys into the comma-separated hex list a miniscript fragment expects.
+fn join_pubkeys(pubkeys: &[XOnlyPublicKey]) -> String {
+    pubkeys
+        .iter()
+        .map(|pk| pk.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Computes balanced taproot depths for `leaf_count` leaves by recursively bisecting
+/// the leaf list, so the resulting depths always sum to 1 (as `2^-depth`) and the
+/// tree finalizes regardless of how many leaves are supplied.
+fn leaf_depths(leaf_count: usize) -> Vec<u8> {
+    fn assign(count: usize, depth: u8, out: &mut Vec<u8>) {
+        if count <= 1 {
+            out.push(depth);
+            return;
+        }
+        let left = count.div_ceil(2);
+        let right = count - left;
+        assign(left, depth + 1, out);
+        assign(right, depth + 1, out);
+    }
+
+    let mut depths = Vec::with_capacity(leaf_count);
+    if leaf_count > 0 {
+        assign(leaf_count, 0, &mut depths);
+    }
+    depths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::secp256k1::{Keypair, SecretKey};
+
+    fn pubkey(byte: u8) -> XOnlyPublicKey {
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&[byte; 32]).expect("valid secret key bytes");
+        Keypair::from_secret_key(&secp, &sk).x_only_public_key().0
+    }
+
+    fn signature(byte: u8) -> SchnorrSignature {
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&[byte; 32]).expect("valid secret key bytes");
+        let keypair = Keypair::from_secret_key(&secp, &sk);
+        let message = bitcoin::secp256k1::Message::from_digest([byte; 32]);
+        secp.sign_schnorr(&message, &keypair)
+    }
+
+    fn connector(
+        keeper_threshold: usize,
+        challenger_threshold: usize,
+        universal_challengers: Vec<XOnlyPublicKey>,
+    ) -> PeginPayoutConnector {
+        PeginPayoutConnector::new(
+            pubkey(1),
+            pubkey(2),
+            vec![pubkey(3), pubkey(4)],
+            universal_challengers,
+            keeper_threshold,
+            challenger_threshold,
+        )
+        .expect("valid connector parameters")
+    }
+
+    #[test]
+    fn to_descriptor_succeeds_for_exact_thresholds() {
+        let c = connector(2, 0, vec![]);
+        assert!(c.to_descriptor().is_ok());
+
+        let c = connector(2, 1, vec![pubkey(5)]);
+        assert!(c.to_descriptor().is_ok());
+    }
+
+    #[test]
+    fn to_descriptor_rejects_partial_keeper_threshold() {
+        let c = connector(1, 0, vec![]);
+        assert!(c.to_descriptor().is_err());
+    }
+
+    #[test]
+    fn to_descriptor_rejects_partial_challenger_threshold() {
+        let c = connector(2, 1, vec![pubkey(5), pubkey(6)]);
+        assert!(c.to_descriptor().is_err());
+    }
+
+    #[test]
+    fn build_script_path_witness_skips_an_offline_keeper() {
+        // keeper_threshold 1 of 2: one keeper may stay offline and still satisfy the quorum.
+        let c = connector(1, 0, vec![]);
+
+        let witness = c
+            .build_script_path_witness(
+                PAYOUT_LEAF_INDEX,
+                vec![
+                    Some(signature(10)), // depositor
+                    Some(signature(11)), // vault provider
+                    Some(signature(12)), // keeper 0 signs
+                    None,                // keeper 1 stays offline
+                ],
+            )
+            .expect("a threshold-satisfying partial signature set must build a witness");
+
+        // 4 signature slots + script + control block.
+        assert_eq!(witness.len(), 6);
+
+        // Signature slots are pushed in reverse script order, so the last key in the
+        // script (the offline keeper) ends up first on the stack. Its slot must be the
+        // empty vector BIP-342 expects, not a fabricated signature burning a sigop.
+        let items: Vec<&[u8]> = witness.iter().collect();
+        assert!(items[0].is_empty());
+    }
+
+    #[test]
+    fn build_script_path_witness_rejects_a_signature_count_mismatch() {
+        // connector(1, 0, vec![]) has 2 keepers, so PAYOUT_LEAF_INDEX expects 4 slots
+        // (depositor, vault provider, keeper 0, keeper 1); supplying 3 must error
+        // rather than silently build a malformed witness.
+        let c = connector(1, 0, vec![]);
+
+        let result = c.build_script_path_witness(
+            PAYOUT_LEAF_INDEX,
+            vec![Some(signature(10)), Some(signature(11)), Some(signature(12))],
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn script_path_witness_round_trips_with_the_sighash_it_was_built_for() {
+        let c = connector(2, 0, vec![]); // exact 2-of-2 keeper quorum
+
+        let prevout = TxOut {
+            value: bitcoin::Amount::from_sat(100_000),
+            script_pubkey: c.generate_taproot_script_pubkey(bitcoin::Network::Regtest),
+        };
+        let tx = Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input: vec![bitcoin::TxIn {
+                previous_output: bitcoin::OutPoint::null(),
+                script_sig: ScriptBuf::new(),
+                sequence: bitcoin::Sequence::ENABLE_RBF_NO_LOCKTIME,
+                witness: Witness::default(),
+            }],
+            output: vec![TxOut {
+                value: bitcoin::Amount::from_sat(99_000),
+                script_pubkey: ScriptBuf::new(),
+            }],
+        };
+
+        let sighash = c
+            .script_path_sighash(&tx, 0, &[prevout], PAYOUT_LEAF_INDEX)
+            .expect("sighash must compute for a well-formed spend");
+        let message = bitcoin::secp256k1::Message::from_digest_slice(sighash.as_ref())
+            .expect("tap sighash is 32 bytes");
+
+        let secp = Secp256k1::new();
+        let sign = |byte: u8| {
+            let sk = SecretKey::from_slice(&[byte; 32]).expect("valid secret key bytes");
+            let keypair = Keypair::from_secret_key(&secp, &sk);
+            secp.sign_schnorr(&message, &keypair)
+        };
+
+        // Keys 1..4 match `connector`'s depositor, vault provider, keeper 0, keeper 1.
+        let depositor_sig = sign(1);
+        let vault_provider_sig = sign(2);
+        let keeper0_sig = sign(3);
+        let keeper1_sig = sign(4);
+
+        let witness = c
+            .build_script_path_witness(
+                PAYOUT_LEAF_INDEX,
+                vec![
+                    Some(depositor_sig),
+                    Some(vault_provider_sig),
+                    Some(keeper0_sig),
+                    Some(keeper1_sig),
+                ],
+            )
+            .expect("a full signature set must build a witness");
+
+        let items: Vec<&[u8]> = witness.iter().collect();
+        assert_eq!(items.len(), 6);
+
+        // Pushed in reverse script order: keeper 1, keeper 0, vault provider, depositor,
+        // then the leaf script and control block land on top of those signature slots.
+        let expected_order = [
+            (keeper1_sig, pubkey(4)),
+            (keeper0_sig, pubkey(3)),
+            (vault_provider_sig, pubkey(2)),
+            (depositor_sig, pubkey(1)),
+        ];
+        for (i, (signature, key)) in expected_order.iter().enumerate() {
+            assert_eq!(items[i], signature.serialize());
+            secp.verify_schnorr(signature, &message, key)
+                .expect("witness slot must hold a valid signature for the spend sighash");
+        }
+
+        assert_eq!(items[4], c.generate_payout_script().as_bytes());
+    }
+}
@@ -16,4 +16,7 @@ pub enum Error {
 
     #[error("Connector error: {0}")]
     ConnectorError(String),
+
+    #[error("Insufficient funds: {0}")]
+    InsufficientFunds(String),
 }
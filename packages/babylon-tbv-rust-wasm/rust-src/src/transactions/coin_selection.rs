@@ -0,0 +1,280 @@
+//! Coin selection for funding a PegIn transaction.
+//!
+//! Implements a branch-and-bound selector (the same strategy used by Bitcoin Core
+//! and most modern wallets) that looks for a changeless match, falling back to a
+//! largest-first accumulation that adds a change output when no exact match exists.
+
+use crate::error::Error;
+use bitcoin::{Amount, OutPoint, ScriptBuf};
+
+/// Approximate extra virtual size contributed by the fixed parts of a transaction
+/// (version, locktime, input/output count varints), excluding inputs and outputs.
+const BASE_VBYTES: u64 = 11;
+/// Approximate virtual size contributed by a single input.
+const INPUT_VBYTES: u64 = 68;
+/// Approximate virtual size contributed by a single output.
+const OUTPUT_VBYTES: u64 = 43;
+
+/// Upper bound on branch-and-bound search steps, to keep worst-case selection bounded.
+const BNB_MAX_TRIES: usize = 100_000;
+
+/// A spendable output available to be used as a transaction input.
+#[derive(Debug, Clone)]
+pub struct Utxo {
+    pub outpoint: OutPoint,
+    pub value: Amount,
+    pub script_pubkey: ScriptBuf,
+}
+
+/// The outcome of a successful coin selection.
+#[derive(Debug, Clone)]
+pub struct SelectionResult {
+    pub selected: Vec<Utxo>,
+    pub change: Option<Amount>,
+}
+
+/// Estimates the fee for a transaction with the given number of inputs and outputs.
+fn estimate_fee(num_inputs: usize, num_outputs: usize, fee_rate_sat_vb: u64) -> Amount {
+    let vbytes = BASE_VBYTES + (num_inputs as u64 * INPUT_VBYTES) + (num_outputs as u64 * OUTPUT_VBYTES);
+    Amount::from_sat(vbytes * fee_rate_sat_vb)
+}
+
+/// Selects UTXOs to cover `target` (plus fees) at `fee_rate_sat_vb`, preferring a
+/// changeless branch-and-bound match and falling back to largest-first accumulation.
+/// `change_spk` is only used to compute the dust threshold for a prospective change
+/// output; no output is constructed here.
+pub(crate) fn select_coins(
+    mut utxos: Vec<Utxo>,
+    target: Amount,
+    fee_rate_sat_vb: u64,
+    change_spk: &ScriptBuf,
+) -> Result<SelectionResult, Error> {
+    utxos.sort_by(|a, b| b.value.cmp(&a.value));
+
+    // Base fee for the final transaction assuming no change output is added.
+    let base_fee = estimate_fee(0, 1, fee_rate_sat_vb);
+    let target_total = target + base_fee;
+    let cost_of_change = Amount::from_sat(OUTPUT_VBYTES * fee_rate_sat_vb);
+    let input_fee = Amount::from_sat(INPUT_VBYTES * fee_rate_sat_vb);
+
+    if let Some(selected) = branch_and_bound(&utxos, target_total, cost_of_change, input_fee) {
+        return Ok(SelectionResult {
+            selected,
+            change: None,
+        });
+    }
+
+    largest_first(utxos, target, fee_rate_sat_vb, change_spk.dust_value())
+}
+
+/// Depth-first branch-and-bound search for a changeless selection.
+///
+/// Each UTXO's "effective value" (its value minus the fee it costs to spend it)
+/// is summed; a selection is accepted as soon as its effective total lands within
+/// `[target_total, target_total + cost_of_change]`, meaning the leftover value is
+/// small enough to simply be absorbed into the fee rather than needing a change
+/// output.
+fn branch_and_bound(
+    utxos: &[Utxo],
+    target_total: Amount,
+    cost_of_change: Amount,
+    input_fee: Amount,
+) -> Option<Vec<Utxo>> {
+    let effective_values: Vec<Amount> = utxos
+        .iter()
+        .map(|u| u.value.checked_sub(input_fee).unwrap_or(Amount::ZERO))
+        .collect();
+
+    let mut tries = 0usize;
+    let mut current_selection = Vec::new();
+    let mut best: Option<Vec<usize>> = None;
+
+    search(
+        &effective_values,
+        0,
+        Amount::ZERO,
+        &mut current_selection,
+        target_total,
+        cost_of_change,
+        &mut best,
+        &mut tries,
+    );
+
+    best.map(|indices| indices.into_iter().map(|i| utxos[i].clone()).collect())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn search(
+    effective_values: &[Amount],
+    index: usize,
+    current: Amount,
+    current_selection: &mut Vec<usize>,
+    target_total: Amount,
+    cost_of_change: Amount,
+    best: &mut Option<Vec<usize>>,
+    tries: &mut usize,
+) {
+    if best.is_some() || *tries >= BNB_MAX_TRIES {
+        return;
+    }
+    *tries += 1;
+
+    if current > target_total + cost_of_change {
+        return;
+    }
+    if current >= target_total {
+        *best = Some(current_selection.clone());
+        return;
+    }
+    if index >= effective_values.len() {
+        return;
+    }
+
+    let remaining: Amount = effective_values[index..].iter().copied().sum();
+    if current + remaining < target_total {
+        return;
+    }
+
+    // Branch: include utxos[index].
+    current_selection.push(index);
+    search(
+        effective_values,
+        index + 1,
+        current + effective_values[index],
+        current_selection,
+        target_total,
+        cost_of_change,
+        best,
+        tries,
+    );
+    current_selection.pop();
+
+    if best.is_some() {
+        return;
+    }
+
+    // Branch: exclude utxos[index].
+    search(
+        effective_values,
+        index + 1,
+        current,
+        current_selection,
+        target_total,
+        cost_of_change,
+        best,
+        tries,
+    );
+}
+
+/// Accumulates UTXOs largest-first until the target plus fees are covered, adding a
+/// change output unless the surplus would be dust.
+fn largest_first(
+    candidates: Vec<Utxo>,
+    target: Amount,
+    fee_rate_sat_vb: u64,
+    dust_limit: Amount,
+) -> Result<SelectionResult, Error> {
+    let mut selected = Vec::new();
+    let mut total = Amount::ZERO;
+
+    for utxo in candidates {
+        total += utxo.value;
+        selected.push(utxo);
+
+        let fee_with_change = estimate_fee(selected.len(), 2, fee_rate_sat_vb);
+        if let Some(surplus) = total.checked_sub(target + fee_with_change) {
+            // Enough to cover a change output; only add one if it clears dust.
+            let change = if surplus >= dust_limit {
+                Some(surplus)
+            } else {
+                None
+            };
+            return Ok(SelectionResult { selected, change });
+        }
+
+        // Not enough for a change output, but check unconditionally (independent of
+        // the with-change branch above) whether the lower no-change fee is covered.
+        let fee_no_change = estimate_fee(selected.len(), 1, fee_rate_sat_vb);
+        if total >= target + fee_no_change {
+            return Ok(SelectionResult {
+                selected,
+                change: None,
+            });
+        }
+    }
+
+    Err(Error::InsufficientFunds(format!(
+        "available {} sat is insufficient to cover {} sat target plus fees",
+        total.to_sat(),
+        target.to_sat()
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::hashes::Hash;
+    use bitcoin::Txid;
+
+    fn utxo(value: u64) -> Utxo {
+        Utxo {
+            outpoint: OutPoint::new(Txid::all_zeros(), 0),
+            value: Amount::from_sat(value),
+            script_pubkey: ScriptBuf::new(),
+        }
+    }
+
+    #[test]
+    fn largest_first_funds_changelessly_when_only_the_no_change_fee_is_covered() {
+        // 101_300 sat clears target + fee_no_change (101_220) but not
+        // target + fee_with_change (101_650); must still succeed without change.
+        let result = largest_first(
+            vec![utxo(101_300)],
+            Amount::from_sat(100_000),
+            10,
+            Amount::from_sat(546),
+        )
+        .expect("a single UTXO covering target + no-change fee must be fundable");
+
+        assert!(result.change.is_none());
+        assert_eq!(result.selected.len(), 1);
+    }
+
+    #[test]
+    fn largest_first_adds_change_when_surplus_clears_dust() {
+        let result = largest_first(
+            vec![utxo(200_000)],
+            Amount::from_sat(100_000),
+            10,
+            Amount::from_sat(546),
+        )
+        .expect("ample funds must be fundable");
+
+        assert!(result.change.is_some());
+    }
+
+    #[test]
+    fn largest_first_errors_when_funds_are_insufficient() {
+        let result = largest_first(
+            vec![utxo(1_000)],
+            Amount::from_sat(100_000),
+            10,
+            Amount::from_sat(546),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn select_coins_prefers_a_changeless_branch_and_bound_match() {
+        // target_total = 100_000 + estimate_fee(0, 1, 10) = 100_540; a UTXO whose
+        // effective value (value - input_fee) lands exactly on target_total sits
+        // inside BnB's [target_total, target_total + cost_of_change] window, so the
+        // primary strategy should find it without ever falling back to largest-first.
+        let result = select_coins(vec![utxo(101_220)], Amount::from_sat(100_000), 10, &ScriptBuf::new())
+            .expect("an exact changeless match must be fundable");
+
+        assert!(result.change.is_none());
+        assert_eq!(result.selected.len(), 1);
+    }
+}
@@ -3,6 +3,8 @@
 //! This module contains only the PegIn transaction which is safe to expose publicly.
 //! No other transaction types (Claim, Assert, Challenge, etc.) are included.
 
+mod coin_selection;
 mod pegin;
 
+pub use coin_selection::Utxo;
 pub use pegin::{PegInParams, PeginTx};
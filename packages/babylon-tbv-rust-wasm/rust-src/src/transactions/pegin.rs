@@ -3,10 +3,12 @@
 //! This is the ONLY transaction type exposed publicly. It creates an unfunded
 //! transaction that sends BTC to a vault-controlled taproot address.
 
+use super::coin_selection::{select_coins, Utxo};
 use crate::connectors::{Connector, PeginPayoutConnector};
 use crate::error::Error;
 use bitcoin::key::XOnlyPublicKey;
-use bitcoin::{Amount, Network, Transaction, TxOut};
+use bitcoin::psbt::Psbt;
+use bitcoin::{Amount, Network, ScriptBuf, Sequence, Transaction, TxIn, TxOut, Witness};
 use std::str::FromStr;
 
 /// Parameters for creating a PegIn transaction from string-based inputs.
@@ -16,6 +18,8 @@ pub struct PegInParams {
     pub vault_provider_pubkey: String,
     pub vault_keeper_pubkeys: Vec<String>,
     pub universal_challenger_pubkeys: Vec<String>,
+    pub keeper_threshold: usize,
+    pub challenger_threshold: usize,
     pub pegin_amount: u64,
     pub network: String,
 }
@@ -61,9 +65,15 @@ impl PeginTx {
         let universal_challengers = universal_challengers?;
 
         // Create connector
-        let connector =
-            PeginPayoutConnector::new(depositor, vault_provider, vault_keepers, universal_challengers)
-                .map_err(|e| Error::ConnectorError(e.to_string()))?;
+        let connector = PeginPayoutConnector::new(
+            depositor,
+            vault_provider,
+            vault_keepers,
+            universal_challengers,
+            params.keeper_threshold,
+            params.challenger_threshold,
+        )
+        .map_err(|e| Error::ConnectorError(e.to_string()))?;
 
         // Create output
         let output = TxOut {
@@ -82,6 +92,75 @@ impl PeginTx {
         Ok(tx)
     }
 
+    /// Creates an unfunded PegIn transaction and wraps it in a [`Psbt`], ready to be
+    /// handed to a funding wallet.
+    ///
+    /// The returned PSBT carries the single pegin output (amount and vault
+    /// `script_pubkey`) but no inputs, so a funder can `add_inputs`, run coin
+    /// selection, and a signer can finalize it without ever reconstructing the
+    /// transaction by hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - A [`PegInParams`] struct containing string-based parameters
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`Psbt`] wrapping the unfunded transaction produced by
+    /// [`Self::new_unfunded_peg_in_tx`].
+    pub fn new_peg_in_psbt(params: PegInParams) -> Result<Psbt, Error> {
+        let tx = Self::new_unfunded_peg_in_tx(params)?;
+
+        Psbt::from_unsigned_tx(tx)
+            .map_err(|e| Error::InvalidTransaction(format!("failed to build PSBT: {}", e)))
+    }
+
+    /// Builds an unfunded PegIn transaction and funds it from the given UTXOs.
+    ///
+    /// Selects inputs via [`coin_selection::select_coins`](super::coin_selection::select_coins)
+    /// to cover the pegin amount plus fees at `fee_rate_sat_vb`, appending a change
+    /// output paying `change_spk` when the surplus clears the dust limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - A [`PegInParams`] struct containing string-based parameters
+    /// * `utxos` - Candidate inputs available for coin selection
+    /// * `fee_rate_sat_vb` - Target fee rate, in satoshis per virtual byte
+    /// * `change_spk` - `script_pubkey` to pay any change to
+    ///
+    /// # Returns
+    ///
+    /// Returns a fully funded, unsigned [`Transaction`].
+    pub fn fund(
+        params: PegInParams,
+        utxos: Vec<Utxo>,
+        fee_rate_sat_vb: u64,
+        change_spk: ScriptBuf,
+    ) -> Result<Transaction, Error> {
+        let mut tx = Self::new_unfunded_peg_in_tx(params)?;
+        let pegin_amount = tx.output[0].value;
+
+        let selection = select_coins(utxos, pegin_amount, fee_rate_sat_vb, &change_spk)?;
+
+        for utxo in &selection.selected {
+            tx.input.push(TxIn {
+                previous_output: utxo.outpoint,
+                script_sig: ScriptBuf::new(),
+                sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+                witness: Witness::default(),
+            });
+        }
+
+        if let Some(change_value) = selection.change {
+            tx.output.push(TxOut {
+                value: change_value,
+                script_pubkey: change_spk,
+            });
+        }
+
+        Ok(tx)
+    }
+
     fn parse_network(network: &str) -> Result<Network, Error> {
         match network.to_lowercase().as_str() {
             "bitcoin" | "mainnet" => Ok(Network::Bitcoin),
@@ -8,6 +8,8 @@
 
 use crate::connectors::{Connector, PeginPayoutConnector};
 use crate::transactions::{PegInParams, PeginTx};
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine as _;
 use bitcoin::key::XOnlyPublicKey;
 use bitcoin::Network;
 use std::str::FromStr;
@@ -35,14 +37,19 @@ impl WasmPeginTx {
     /// * `vault_provider_pubkey` - Vault provider's x-only public key (64 hex chars)
     /// * `vault_keeper_pubkeys` - Array of vault keeper x-only public keys
     /// * `universal_challenger_pubkeys` - Array of universal challenger x-only public keys
+    /// * `keeper_threshold` - Number of vault keepers that must sign (1..=vault_keeper_pubkeys.len())
+    /// * `challenger_threshold` - Number of universal challengers that must sign (0 if none configured)
     /// * `pegin_amount` - Amount in satoshis
     /// * `network` - Network ("mainnet", "testnet", "regtest", "signet")
     #[wasm_bindgen(constructor)]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         depositor_pubkey: String,
         vault_provider_pubkey: String,
         vault_keeper_pubkeys: Vec<String>,
         universal_challenger_pubkeys: Vec<String>,
+        keeper_threshold: usize,
+        challenger_threshold: usize,
         pegin_amount: u64,
         network: String,
     ) -> Result<WasmPeginTx, JsValue> {
@@ -51,6 +58,8 @@ impl WasmPeginTx {
             vault_provider_pubkey,
             vault_keeper_pubkeys,
             universal_challenger_pubkeys,
+            keeper_threshold,
+            challenger_threshold,
             pegin_amount,
             network,
         };
@@ -84,6 +93,15 @@ impl WasmPeginTx {
     pub fn get_vault_value(&self) -> u64 {
         self.inner.output[0].value.to_sat()
     }
+
+    /// Get the transaction as a base64-encoded PSBT, ready for a wallet to fund and sign
+    #[wasm_bindgen(js_name = toPsbt)]
+    pub fn to_psbt(&self) -> Result<String, JsValue> {
+        let psbt = bitcoin::psbt::Psbt::from_unsigned_tx(self.inner.clone())
+            .map_err(|e| JsValue::from_str(&format!("PSBT error: {}", e)))?;
+
+        Ok(BASE64_STANDARD.encode(psbt.serialize()))
+    }
 }
 
 // ==================== WasmPeginPayoutConnector ====================
@@ -105,12 +123,16 @@ impl WasmPeginPayoutConnector {
     /// * `vault_provider` - Vault provider's x-only public key (64 hex chars)
     /// * `vault_keepers` - Array of vault keeper x-only public keys
     /// * `universal_challengers` - Array of universal challenger x-only public keys
+    /// * `keeper_threshold` - Number of vault keepers that must sign (1..=vault_keepers.len())
+    /// * `challenger_threshold` - Number of universal challengers that must sign (0 if none configured)
     #[wasm_bindgen(constructor)]
     pub fn new(
         depositor: String,
         vault_provider: String,
         vault_keepers: Vec<String>,
         universal_challengers: Vec<String>,
+        keeper_threshold: usize,
+        challenger_threshold: usize,
     ) -> Result<WasmPeginPayoutConnector, JsValue> {
         let depositor_pubkey = parse_pubkey(&depositor)?;
         let vault_provider_pubkey = parse_pubkey(&vault_provider)?;
@@ -122,6 +144,8 @@ impl WasmPeginPayoutConnector {
             vault_provider_pubkey,
             vault_keeper_pubkeys,
             universal_challenger_pubkeys,
+            keeper_threshold,
+            challenger_threshold,
         )
         .map_err(|e| JsValue::from_str(&format!("PeginPayoutConnector error: {}", e)))?;
 
@@ -155,6 +179,105 @@ impl WasmPeginPayoutConnector {
     pub fn get_taproot_script_hash(&self) -> String {
         self.inner.generate_taproot_script_hash().to_string()
     }
+
+    /// Get the `tr()` output descriptor reproducing this connector's address and spend policy
+    #[wasm_bindgen(js_name = getDescriptor)]
+    pub fn get_descriptor(&self) -> Result<String, JsValue> {
+        self.inner
+            .to_descriptor()
+            .map_err(|e| JsValue::from_str(&format!("Descriptor error: {}", e)))
+    }
+
+    /// Compute the script-path sighash for spending one of this connector's leaves
+    ///
+    /// # Arguments
+    /// * `tx_hex` - The spending transaction, consensus-encoded as hex
+    /// * `input_index` - Index of the input spending the pegin output
+    /// * `prevout_values` - Value (in satoshis) of every previous output, in input order
+    /// * `prevout_script_pubkeys` - Hex-encoded `script_pubkey` of every previous output, in input order
+    /// * `leaf_index` - Which leaf is being spent (0 = full payout path, 1 = cooperative fast path)
+    #[wasm_bindgen(js_name = getScriptPathSighash)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_script_path_sighash(
+        &self,
+        tx_hex: String,
+        input_index: usize,
+        prevout_values: Vec<u64>,
+        prevout_script_pubkeys: Vec<String>,
+        leaf_index: usize,
+    ) -> Result<String, JsValue> {
+        let tx_bytes = hex::decode(&tx_hex).map_err(|e| JsValue::from_str(&format!("Invalid tx hex: {}", e)))?;
+        let tx: bitcoin::Transaction = bitcoin::consensus::deserialize(&tx_bytes)
+            .map_err(|e| JsValue::from_str(&format!("Invalid transaction: {}", e)))?;
+
+        if prevout_values.len() != prevout_script_pubkeys.len() {
+            return Err(JsValue::from_str(
+                "prevout_values and prevout_script_pubkeys must be the same length",
+            ));
+        }
+
+        let prevouts: Result<Vec<bitcoin::TxOut>, JsValue> = prevout_values
+            .into_iter()
+            .zip(prevout_script_pubkeys)
+            .map(|(value, spk_hex)| {
+                let spk_bytes =
+                    hex::decode(&spk_hex).map_err(|e| JsValue::from_str(&format!("Invalid script_pubkey hex: {}", e)))?;
+                Ok(bitcoin::TxOut {
+                    value: bitcoin::Amount::from_sat(value),
+                    script_pubkey: bitcoin::ScriptBuf::from_bytes(spk_bytes),
+                })
+            })
+            .collect();
+        let prevouts = prevouts?;
+
+        let sighash = self
+            .inner
+            .script_path_sighash(&tx, input_index, &prevouts, leaf_index)
+            .map_err(|e| JsValue::from_str(&format!("Sighash error: {}", e)))?;
+
+        Ok(sighash.to_string())
+    }
+
+    /// Build the final witness for a script-path spend of one of this connector's leaves
+    ///
+    /// # Arguments
+    /// * `leaf_index` - Which leaf is being spent (0 = full payout path, 1 = cooperative fast path)
+    /// * `signatures_hex` - Hex-encoded 64-byte Schnorr signatures, in the order the
+    ///   keys appear in that leaf's script (for the full payout path: depositor,
+    ///   vault provider, then each keeper/challenger; for the fast path: depositor,
+    ///   vault provider). Pass an empty string for a keeper/challenger who didn't
+    ///   sign; the depositor and vault-provider slots are always required.
+    ///
+    /// # Returns
+    /// The consensus-encoded witness, as hex.
+    #[wasm_bindgen(js_name = buildScriptPathWitness)]
+    pub fn build_script_path_witness(
+        &self,
+        leaf_index: usize,
+        signatures_hex: Vec<String>,
+    ) -> Result<String, JsValue> {
+        let signatures: Result<Vec<Option<bitcoin::secp256k1::schnorr::Signature>>, JsValue> = signatures_hex
+            .into_iter()
+            .map(|sig_hex| {
+                if sig_hex.is_empty() {
+                    return Ok(None);
+                }
+                let sig_bytes =
+                    hex::decode(&sig_hex).map_err(|e| JsValue::from_str(&format!("Invalid signature hex: {}", e)))?;
+                bitcoin::secp256k1::schnorr::Signature::from_slice(&sig_bytes)
+                    .map(Some)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid signature: {}", e)))
+            })
+            .collect();
+        let signatures = signatures?;
+
+        let witness = self
+            .inner
+            .build_script_path_witness(leaf_index, signatures)
+            .map_err(|e| JsValue::from_str(&format!("Witness error: {}", e)))?;
+
+        Ok(hex::encode(bitcoin::consensus::serialize(&witness)))
+    }
 }
 
 // ==================== Helper Functions ====================